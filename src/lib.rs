@@ -1,18 +1,30 @@
 #![allow(non_snake_case)]
+// pyo3 0.18 emits nested impls for number protocol methods such as __pow__
+#![allow(non_local_definitions)]
 
 use pyo3::prelude::*;
+use pyo3::basic::CompareOp;
 use indexmap::IndexMap;
 use regex::Regex;
 use lazy_static::lazy_static;
 use pyo3::types::{PyDict, PyList};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Mul;
 
 /// A Python module implemented in Rust.
 #[pymodule]
 fn pulp_rs(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<OptimizedClass>()?;
     m.add_class::<LpElement>()?;
+    m.add_class::<LpVariable>()?;
     m.add_class::<LpAffineExpression>()?;
+    m.add_class::<TermOrder>()?;
+    m.add_function(wrap_pyfunction!(value, m)?)?;
+    m.add_function(wrap_pyfunction!(lpDot, m)?)?;
     Ok(())
 }
 
@@ -41,6 +53,11 @@ trait HasValue {
     fn valueOrDefault(&self) -> Option<f64>;
 }
 
+// maps -0.0 to 0.0 so formatted coefficients never carry a stray minus sign
+fn normalize_coeff(x: f64) -> f64 {
+    x + 0.0
+}
+
 
 #[pyclass]
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -70,7 +87,17 @@ impl LpElement {
     }
 
     fn __hash__(&self) -> PyResult<isize> {
-        Ok(self.name.as_ref().map_or(0, |n| n.len() as isize))
+        let mut hasher = DefaultHasher::new();
+        self.name.hash(&mut hasher);
+        Ok(hasher.finish() as isize)
+    }
+
+    fn __richcmp__(&self, other: PyRef<LpElement>, op: CompareOp, py: Python) -> PyObject {
+        match op {
+            CompareOp::Eq => (self.name == other.name).into_py(py),
+            CompareOp::Ne => (self.name != other.name).into_py(py),
+            _ => py.NotImplemented(),
+        }
     }
 
     fn __bool__(&self) -> PyResult<bool> {
@@ -87,12 +114,13 @@ impl HasValue for LpElement {
 impl LpElement {
     fn sanitize_name(name: &str) -> String {
         lazy_static! {
-            static ref ILLEGAL_CHARS: Regex = Regex::new(r"[-+\[\] ->/]").unwrap();
+            // PuLP's illegal characters "-+[] ->/", without " ->" turning into a range over digits
+            static ref ILLEGAL_CHARS: Regex = Regex::new(r"[-+\[\] >/]").unwrap();
         }
         if ILLEGAL_CHARS.is_match(name) {
             println!("Warning: The name {} has illegal characters that will be replaced by _", name);
         }
-        ILLEGAL_CHARS.replace_all(name, "________").to_string()
+        ILLEGAL_CHARS.replace_all(name, "_").to_string()
     }
 }
 
@@ -120,6 +148,11 @@ struct LpVariable {
     var_value: Option<f64>,
     #[pyo3(get, set)]
     dj: Option<f64>,
+    // expression linking the variable to constraints for column-wise modelling
+    #[pyo3(get)]
+    expression: Option<LpAffineExpression>,
+    // bounds replaced by toBinary, restored by toContinuous
+    original_bounds: Option<(Option<f64>, Option<f64>)>,
 }
 
 impl HasValue for LpVariable {
@@ -133,7 +166,7 @@ impl HasValue for LpVariable {
 impl LpVariable {
     #[new]
     #[pyo3(signature = (name, low_bound=None, up_bound=None, cat="Continuous", e=None))]
-    fn new(name: Option<String>, low_bound: Option<f64>, up_bound: Option<f64>, cat: &str, e: Option<&PyAny>) -> PyResult<Self> {
+    fn new(name: Option<String>, low_bound: Option<f64>, up_bound: Option<f64>, cat: &str, e: Option<LpAffineExpression>) -> PyResult<Self> {
         let mut var = LpVariable {
             name,
             low_bound,
@@ -141,18 +174,159 @@ impl LpVariable {
             cat: cat.to_string(),
             var_value: None,
             dj: None,
+            expression: None,
+            original_bounds: None,
         };
 
+        var.validate()?;
         if var.cat == "Binary" {
+            let outside = |b: Option<f64>| b.is_some_and(|b| b != 0.0 && b != 1.0);
+            if outside(var.low_bound) || outside(var.up_bound) {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Binary variable {} must have bounds of 0 or 1",
+                    var.name.clone().unwrap_or_default()
+                )));
+            }
             var.low_bound = Some(0.0);
             var.up_bound = Some(1.0);
             var.cat = "Integer".to_string();
         }
+        if let Some(e) = e {
+            var.add_expression(e);
+        }
         Ok(var)
     }
+
+    #[staticmethod]
+    #[pyo3(signature = (name, start, end, low_bound=None, up_bound=None, cat="Continuous"))]
+    fn range(name: &str, start: i64, end: i64, low_bound: Option<f64>, up_bound: Option<f64>, cat: &str) -> PyResult<Vec<LpVariable>> {
+        (start..end)
+            .map(|i| {
+                let var_name = LpElement::sanitize_name(&format!("{}_{}", name, i));
+                LpVariable::new(Some(var_name), low_bound, up_bound, cat, None)
+            })
+            .collect()
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
+    fn toBinary(&mut self) {
+        if self.original_bounds.is_none() {
+            self.original_bounds = Some((self.low_bound, self.up_bound));
+        }
+        self.low_bound = Some(0.0);
+        self.up_bound = Some(1.0);
+        self.cat = "Integer".to_string();
+    }
+
+    fn toInteger(&mut self) {
+        self.cat = "Integer".to_string();
+    }
+
+    fn toContinuous(&mut self) {
+        self.restore_original_bounds();
+        self.cat = "Continuous".to_string();
+    }
+
+    #[getter(varValue)]
+    fn get_varValue(&self) -> Option<f64> {
+        self.var_value
+    }
+
+    #[setter(varValue)]
+    fn set_varValue(&mut self, value: Option<f64>) {
+        self.var_value = value;
+    }
+
+    #[getter(lowBound)]
+    fn get_lowBound(&self) -> Option<f64> {
+        self.low_bound
+    }
+
+    #[setter(lowBound)]
+    fn set_lowBound(&mut self, value: Option<f64>) {
+        self.low_bound = value;
+    }
+
+    #[getter(upBound)]
+    fn get_upBound(&self) -> Option<f64> {
+        self.up_bound
+    }
+
+    #[setter(upBound)]
+    fn set_upBound(&mut self, value: Option<f64>) {
+        self.up_bound = value;
+    }
+
+    fn add_expression(&mut self, e: LpAffineExpression) {
+        self.expression = Some(e);
+    }
+
+    fn validate(&self) -> PyResult<()> {
+        if let (Some(low), Some(up)) = (self.low_bound, self.up_bound) {
+            if low > up {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Lower bound {} is greater than upper bound {} for variable {}",
+                    low,
+                    up,
+                    self.name.clone().unwrap_or_default()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn feasible_round(&self) -> PyResult<f64> {
+        let value = self.var_value.ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>("Variable has no value to round")
+        })?;
+        if !value.is_finite() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Cannot round non-finite value {} of {}",
+                value,
+                self.name.clone().unwrap_or_default()
+            )));
+        }
+        // nearest integers inside the bounds
+        let low = self.low_bound.map_or(f64::NEG_INFINITY, f64::ceil);
+        let up = self.up_bound.map_or(f64::INFINITY, f64::floor);
+        if low > up {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "No integer value fits within the bounds of {}",
+                self.name.clone().unwrap_or_default()
+            )));
+        }
+        Ok(value.round().max(low).min(up))
+    }
+}
+
+impl LpVariable {
+    fn restore_original_bounds(&mut self) {
+        // bounds edited since toBinary are the user's, so leave them alone
+        if let Some((low, up)) = self.original_bounds.take() {
+            if self.low_bound == Some(0.0) && self.up_bound == Some(1.0) {
+                self.low_bound = low;
+                self.up_bound = up;
+            }
+        }
+    }
 }
 
 
+#[pyclass]
+#[derive(Clone, Copy, PartialEq)]
+enum TermOrder {
+    Alphabetical,
+    Insertion,
+    ByCoefficient,
+}
+
 #[pyclass]
 #[derive(Clone)]
 struct LpAffineExpression {
@@ -187,12 +361,12 @@ impl LpAffineExpression {
                 for (k, v) in dict.iter() {
                     let element: LpElement = k.extract()?;
                     let coeff: f64 = v.extract()?;
-                    expr.terms.insert(element, coeff);
+                    expr.addTerm(element, coeff);
                 }
             } else if let Ok(list) = e.downcast::<PyList>() {
                 for item in list.iter() {
                     let (element, coeff): (LpElement, f64) = item.extract()?;
-                    expr.terms.insert(element, coeff);
+                    expr.addTerm(element, coeff);
                 }
             } else if let Ok(element) = e.extract::<LpElement>() {
                 expr.terms.insert(element, 1.0);
@@ -204,6 +378,14 @@ impl LpAffineExpression {
         Ok(expr)
     }
 
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
     #[getter]
     fn get_name(&self) -> Option<String> {
         self.name.clone()
@@ -231,10 +413,39 @@ impl LpAffineExpression {
         self.terms.keys().next().cloned()
     }
 
+    fn coefficient(&self, name: &str) -> Option<f64> {
+        self.terms
+            .iter()
+            .find(|(k, _)| k.name.as_deref() == Some(name))
+            .map(|(_, &x)| x)
+    }
+
     fn __bool__(&self) -> PyResult<bool> {
         Ok(self.constant != 0.0 || !self.terms.is_empty())
     }
 
+    fn __pow__(&self, other: &PyAny, _modulo: Option<&PyAny>) -> PyResult<Self> {
+        match other.extract::<f64>() {
+            Ok(1.0) => Ok(self.clone()),
+            Ok(0.0) => Ok(LpAffineExpression {
+                constant: 1.0,
+                name: None,
+                terms: IndexMap::new(),
+            }),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Linear expressions cannot be raised to a power",
+            )),
+        }
+    }
+
+    fn value(&self) -> Option<f64> {
+        let mut s = self.constant;
+        for (v, &x) in self.terms.iter() {
+            s += v.valueOrDefault()? * x;
+        }
+        Some(s)
+    }
+
     fn valueOrDefault(&self) -> f64 {
         let mut s = self.constant;
         for (v, &x) in self.terms.iter() {
@@ -245,32 +456,110 @@ impl LpAffineExpression {
         s
     }
 
+    fn value_with(&self, values: HashMap<String, f64>) -> f64 {
+        let mut s = self.constant;
+        for (v, &x) in self.terms.iter() {
+            let value = v
+                .name
+                .as_ref()
+                .and_then(|n| values.get(n).copied())
+                .or_else(|| v.valueOrDefault());
+            if let Some(value) = value {
+                s += value * x;
+            }
+        }
+        s
+    }
+
     fn addTerm(&mut self, key: LpElement, value: f64) {
         *self.terms.entry(key).or_insert(0.0) += value;
     }
 
+    #[pyo3(signature = (other, eps=1e-9))]
+    fn approx_eq(&self, other: PyRef<LpAffineExpression>, eps: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= eps;
+        close(self.constant, other.constant)
+            && self
+                .terms
+                .iter()
+                .all(|(k, &x)| close(x, other.terms.get(k).copied().unwrap_or(0.0)))
+            && other
+                .terms
+                .iter()
+                .all(|(k, &x)| close(x, self.terms.get(k).copied().unwrap_or(0.0)))
+    }
+
+    fn simplify(&mut self) {
+        self.terms.retain(|_, x| x.abs() > 1e-12);
+    }
+
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        for (k, v) in &self.terms {
+            dict.set_item(k.clone().into_py(py), v)?;
+        }
+        Ok(dict.into())
+    }
+
+    /// PuLP's `toDict()` shape: `[{"name": ..., "value": ...}]` ordered by name.
+    fn to_dict_list(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        let mut result = Vec::new();
+        for k in self.sorted_keys()? {
+            let dict = PyDict::new(py);
+            dict.set_item("name", &k.name)?;
+            dict.set_item("value", self.terms[&k])?;
+            result.push(dict.into());
+        }
+        Ok(result)
+    }
+
+    fn terms(&self) -> Vec<(LpElement, f64)> {
+        self.terms.iter().map(|(k, &v)| (k.clone(), v)).collect()
+    }
+
 
     fn __str__(&self) -> PyResult<String> {
+        self.to_string_ordered(TermOrder::Alphabetical)
+    }
+
+    #[pyo3(signature = (order=TermOrder::Alphabetical))]
+    fn to_string_ordered(&self, order: TermOrder) -> PyResult<String> {
+        let keys = match order {
+            TermOrder::Alphabetical => self.sorted_keys()?,
+            TermOrder::Insertion => self.terms.keys().cloned().collect(),
+            TermOrder::ByCoefficient => {
+                // largest magnitude first, ties keep insertion order
+                let mut keys: Vec<_> = self.terms.keys().cloned().collect();
+                keys.sort_by(|a, b| {
+                    self.terms[b]
+                        .abs()
+                        .partial_cmp(&self.terms[a].abs())
+                        .unwrap_or(Ordering::Equal)
+                });
+                keys
+            }
+        };
+
         let mut s = String::new();
-        for v in self.sorted_keys()? {
-            let val = self.terms.get(&v).unwrap();
+        for v in keys {
+            let val = normalize_coeff(*self.terms.get(&v).unwrap());
 
             if s.is_empty() {
-                if *val < 0.0 {
+                if val < 0.0 {
                     s.push('-');
                 }
-                if *val != 1.0 {
+                if val != 1.0 {
                     s.push_str(&format!("{}", val.abs()));
                     s.push('*');
                 }
                 s.push_str(&v.to_string());
             } else {
-                if *val < 0.0 {
+                if val < 0.0 {
                     s.push_str(" - ");
                 } else {
                     s.push_str(" + ");
                 }
-                if *val != 1.0 {
+                if val != 1.0 {
                     s.push_str(&format!("{}", val.abs()));
                     s.push('*');
                 }
@@ -278,13 +567,14 @@ impl LpAffineExpression {
             }
         }
 
+        let constant = normalize_coeff(self.constant);
         if s.is_empty() {
-            s = self.constant.to_string();
+            s = constant.to_string();
         } else {
-            if self.constant < 0.0 {
-                s.push_str(&format!(" - {}", self.constant.abs()));
-            } else if self.constant > 0.0 {
-                s.push_str(&format!(" + {}", self.constant));
+            if constant < 0.0 {
+                s.push_str(&format!(" - {}", constant.abs()));
+            } else if constant > 0.0 {
+                s.push_str(&format!(" + {}", constant));
             }
         }
         Ok(s)
@@ -292,6 +582,7 @@ impl LpAffineExpression {
 
     fn sorted_keys(&self) -> PyResult<Vec<LpElement>> {
         let mut keys: Vec<_> = self.terms.keys().cloned().collect();
+        // stable sort over IndexMap order, so equal names keep insertion order
         keys.sort_by_key(|k| k.name.clone().unwrap_or_default());
         Ok(keys)
     }
@@ -399,14 +690,6 @@ impl LpAffineExpression {
     //     result
     // }
 
-    // fn to_dict(&self, py: Python) -> PyResult<PyObject> {
-    //     let dict = PyDict::new(py);
-    //     for (k, v) in &self.terms {
-    //         dict.set_item(k, v)?;
-    //     }
-    //     Ok(dict.into())
-    // }
-
     // fn is_atomic(&self) -> bool {
     //     self.terms.len() == 1 && self.constant == 0.0 && self.terms.values().next().unwrap() == &1.0
     // }
@@ -489,10 +772,83 @@ impl LpAffineExpression {
     // }
 }
 
+impl From<&LpVariable> for LpElement {
+    fn from(var: &LpVariable) -> Self {
+        LpElement { name: var.name.clone() }
+    }
+}
+
+impl From<&LpElement> for LpElement {
+    fn from(element: &LpElement) -> Self {
+        element.clone()
+    }
+}
+
+impl From<f64> for LpAffineExpression {
+    fn from(constant: f64) -> Self {
+        LpAffineExpression {
+            constant,
+            name: None,
+            terms: IndexMap::new(),
+        }
+    }
+}
+
+impl From<&LpElement> for LpAffineExpression {
+    fn from(element: &LpElement) -> Self {
+        let mut expr = LpAffineExpression::from(0.0);
+        expr.addTerm(element.clone(), 1.0);
+        expr
+    }
+}
+
+impl From<&LpVariable> for LpAffineExpression {
+    fn from(var: &LpVariable) -> Self {
+        LpAffineExpression::from((var, 1.0))
+    }
+}
+
+impl From<(&LpVariable, f64)> for LpAffineExpression {
+    fn from((var, coeff): (&LpVariable, f64)) -> Self {
+        LpAffineExpression::from(vec![(var, coeff)])
+    }
+}
+
+impl From<Vec<(&LpVariable, f64)>> for LpAffineExpression {
+    fn from(terms: Vec<(&LpVariable, f64)>) -> Self {
+        let mut expr = LpAffineExpression::from(0.0);
+        for (var, coeff) in terms {
+            expr.addTerm(LpElement::from(var), coeff);
+        }
+        expr
+    }
+}
+
+impl Mul for &LpAffineExpression {
+    type Output = Result<LpAffineExpression, String>;
+
+    fn mul(self, other: &LpAffineExpression) -> Self::Output {
+        // only a constant side keeps the product linear, as in PuLP's __mul__
+        let (scale, expr) = if other.terms.is_empty() {
+            (other.constant, self)
+        } else if self.terms.is_empty() {
+            (self.constant, other)
+        } else {
+            return Err("Non-constant expressions cannot be multiplied".to_string());
+        };
+        let mut result = LpAffineExpression::from(self.constant * other.constant);
+        for (v, x) in &expr.terms {
+            result.terms.insert(v.clone(), scale * x);
+        }
+        Ok(result)
+    }
+}
+
 impl fmt::Display for LpAffineExpression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut s = String::new();
         for (v, &x) in self.terms.iter().filter(|(_, &x)| x != 0.0) {
+            let x = normalize_coeff(x);
             if x < 0.0 {
                 if !s.is_empty() {
                     s.push_str(" - ");
@@ -510,14 +866,15 @@ impl fmt::Display for LpAffineExpression {
                 s.push_str(&format!("{}*{}", abs_x, v.name.as_ref().unwrap_or(&String::new())));
             }
         }
+        let constant = normalize_coeff(self.constant);
         if s.is_empty() {
-            write!(f, "{}", self.constant)?;
+            write!(f, "{}", constant)?;
         } else {
             write!(f, "{}", s)?;
-            if self.constant < 0.0 {
-                write!(f, " - {}", -self.constant)?;
-            } else if self.constant > 0.0 {
-                write!(f, " + {}", self.constant)?;
+            if constant < 0.0 {
+                write!(f, " - {}", -constant)?;
+            } else if constant > 0.0 {
+                write!(f, " + {}", constant)?;
             }
         }
         Ok(())
@@ -525,6 +882,54 @@ impl fmt::Display for LpAffineExpression {
 }
 
 
+/// Value of a number, variable or expression, mirroring PuLP's `value()`.
+#[pyfunction]
+fn value(obj: &PyAny) -> PyResult<Option<f64>> {
+    if obj.is_none() {
+        Ok(None)
+    } else if let Ok(var) = obj.extract::<PyRef<LpVariable>>() {
+        Ok(var.var_value)
+    } else if let Ok(expr) = obj.extract::<PyRef<LpAffineExpression>>() {
+        Ok(expr.value())
+    } else if let Ok(element) = obj.extract::<PyRef<LpElement>>() {
+        Ok(element.valueOrDefault())
+    } else {
+        Ok(Some(obj.extract::<f64>()?))
+    }
+}
+
+
+fn lp_dot<T>(coeffs: &[f64], vars: &[T]) -> PyResult<LpAffineExpression>
+where
+    for<'a> &'a T: Into<LpElement>,
+{
+    if coeffs.len() != vars.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "lpDot needs as many coefficients as variables, got {} and {}",
+            coeffs.len(),
+            vars.len()
+        )));
+    }
+    let mut expr = LpAffineExpression::from(0.0);
+    for (&coeff, var) in coeffs.iter().zip(vars) {
+        expr.addTerm(var.into(), coeff);
+    }
+    Ok(expr)
+}
+
+/// Inner product of coefficients and variables, mirroring PuLP's `lpDot()`.
+#[pyfunction]
+fn lpDot(coeffs: Vec<f64>, vars: Vec<&PyAny>) -> PyResult<LpAffineExpression> {
+    let elements = vars
+        .into_iter()
+        .map(|v| match v.extract::<PyRef<LpVariable>>() {
+            Ok(var) => Ok(LpElement::from(&*var)),
+            Err(_) => v.extract::<LpElement>(),
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    lp_dot(&coeffs, &elements)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -588,4 +993,68 @@ mod tests {
     //         assert_eq!(negation.constant, -5.0);
     //     });
     // }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_lp_affine_expression_from() {
+        let var = |name: &str| LpVariable {
+            name: Some(name.to_string()),
+            low_bound: None,
+            up_bound: None,
+            cat: "Continuous".to_string(),
+            var_value: None,
+            dj: None,
+            expression: None,
+            original_bounds: None,
+        };
+        let x = var("x");
+        let y = var("y");
+
+        let expr = LpAffineExpression::from(5.0);
+        assert_eq!(expr.constant, 5.0);
+        assert!(expr.terms.is_empty());
+
+        let expr = LpAffineExpression::from(&LpElement::from(&x));
+        assert_eq!(expr.coefficient("x"), Some(1.0));
+
+        let expr = LpAffineExpression::from(&x);
+        assert_eq!(expr.coefficient("x"), Some(1.0));
+
+        let expr = LpAffineExpression::from((&y, 3.0));
+        assert_eq!(expr.coefficient("y"), Some(3.0));
+        assert_eq!(expr.constant, 0.0);
+
+        let expr = LpAffineExpression::from(vec![(&x, 2.0), (&y, 3.0), (&x, 1.0)]);
+        assert_eq!(expr.coefficient("x"), Some(3.0));
+        assert_eq!(expr.coefficient("y"), Some(3.0));
+        assert_eq!(expr.terms.len(), 2);
+    }
+
+    #[test]
+    fn test_lp_affine_expression_mul() {
+        let var = |name: &str| LpVariable {
+            name: Some(name.to_string()),
+            low_bound: None,
+            up_bound: None,
+            cat: "Continuous".to_string(),
+            var_value: None,
+            dj: None,
+            expression: None,
+            original_bounds: None,
+        };
+        let x = var("x");
+        let y = var("y");
+
+        let mut x_plus_one = LpAffineExpression::from(&x);
+        x_plus_one.constant = 1.0;
+        let product = (&LpAffineExpression::from(2.0) * &x_plus_one).unwrap();
+        assert_eq!(product.coefficient("x"), Some(2.0));
+        assert_eq!(product.constant, 2.0);
+
+        let product = (&x_plus_one * &LpAffineExpression::from(3.0)).unwrap();
+        assert_eq!(product.coefficient("x"), Some(3.0));
+        assert_eq!(product.constant, 3.0);
+
+        let product = &LpAffineExpression::from(&x) * &LpAffineExpression::from(&y);
+        assert_eq!(product.err().as_deref(), Some("Non-constant expressions cannot be multiplied"));
+    }
+}
@@ -1,3 +1,5 @@
+import copy
+import json
 import pytest
 import pulp_rs
 import pulp
@@ -24,6 +26,24 @@ def test_lp_element():
     # Test behaviour matches original library
     assert str(pulp.LpElement("x")) == str(element)
 
+def test_lp_element_sanitize_name():
+    # digits are legal, illegal characters are still replaced
+    assert pulp_rs.LpElement("x1").name == "x1"
+    assert pulp_rs.LpElement("x_0").name == "x_0"
+    assert pulp_rs.LpElement("a b").name == "a_b"
+    assert pulp_rs.LpElement("y[1]").name == "y_1_"
+    assert pulp_rs.LpElement("a->b").name == "a__b"
+    assert [x.name for x in pulp_rs.LpVariable.range("x", -2, 1)] == ["x__2", "x__1", "x_0"]
+
+def test_lp_element_equality():
+    x1 = pulp_rs.LpElement("x")
+    x2 = pulp_rs.LpElement("x")
+    assert x1 == x2
+    assert x1 != pulp_rs.LpElement("y")
+    assert hash(x1) == hash(x2)
+    assert x2 in {x1}
+    assert len({x1, x2, pulp_rs.LpElement("y")}) == 2
+
 def test_lp_affine_expression():
     # Test creation with constant and name
     expr = pulp_rs.LpAffineExpression(constant=5.0, name="expr")
@@ -77,6 +97,263 @@ def test_lp_affine_str():
     assert str(expr_with_vars) == str(pulp_expr_with_vars)
 
     
+def test_lp_affine_str_negative_zero():
+    x = pulp_rs.LpElement("x")
+    expr = pulp_rs.LpAffineExpression({x: -0.0}, constant=1.0)
+    # -0.0 coefficients never carried a minus; only the constant path changed
+    assert str(expr) == "0*x + 1"
+    assert str(pulp_rs.LpAffineExpression(constant=-0.0)) == "0"
+
+
+def test_lp_affine_pow():
+    x = pulp_rs.LpElement("x")
+    expr = pulp_rs.LpAffineExpression({x: 2}, constant=1.0)
+    assert str(expr ** 1) == str(expr)
+    assert str(expr ** 0) == "1"
+    with pytest.raises(TypeError, match="cannot be raised to a power"):
+        expr ** 2
+
+
+def test_lp_affine_value_with():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    expr = pulp_rs.LpAffineExpression({x: 2, y: 3}, constant=1.0)
+    assert expr.value_with({"x": 1.0, "y": 2.0}) == 9.0
+    # missing names fall back to valueOrDefault
+    assert expr.value_with({"x": 1.0}) == 3.0
+
+
+def test_lp_affine_repeated_terms():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    expr = pulp_rs.LpAffineExpression([(x, 2), (y, 1), (x, 3)])
+    assert str(expr) == "5*x + y"
+
+
+def test_lp_affine_copy():
+    x = pulp_rs.LpElement("x")
+    expr = pulp_rs.LpAffineExpression({x: 2}, constant=1.5)
+
+    for clone in (copy.copy(expr), copy.deepcopy(expr)):
+        clone.addTerm(pulp_rs.LpElement("y"), 3)
+        clone.constant = 4.0
+        assert str(expr) == "2*x + 1.5"
+
+    v = pulp_rs.LpVariable("v", low_bound=0, up_bound=5)
+    for clone in (copy.copy(v), copy.deepcopy(v)):
+        clone.up_bound = 10
+        assert v.up_bound == 5
+
+
+def test_lp_affine_coefficient():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    expr = pulp_rs.LpAffineExpression({x: 2, y: -3}, constant=1.0)
+    assert expr.coefficient("x") == 2
+    assert expr.coefficient("y") == -3
+    assert expr.coefficient("z") is None
+
+
+def test_lp_affine_approx_eq():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    expr = pulp_rs.LpAffineExpression({x: 2, y: 3}, constant=1.0)
+
+    assert expr.approx_eq(pulp_rs.LpAffineExpression({x: 2 + 1e-12, y: 3}, constant=1.0))
+    assert not expr.approx_eq(pulp_rs.LpAffineExpression({x: 2.1, y: 3}, constant=1.0))
+    assert expr.approx_eq(pulp_rs.LpAffineExpression({x: 2.1, y: 3}, constant=1.0), eps=0.2)
+    # missing terms count as zero
+    assert expr.approx_eq(pulp_rs.LpAffineExpression({x: 2, y: 3, pulp_rs.LpElement("z"): 1e-12}, constant=1.0))
+    assert not expr.approx_eq(pulp_rs.LpAffineExpression({x: 2}, constant=1.0))
+
+
+def test_lp_affine_str_ordered():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    z = pulp_rs.LpElement("z")
+    expr = pulp_rs.LpAffineExpression([(y, 1), (z, -5), (x, 3)])
+
+    assert expr.to_string_ordered() == str(expr) == "3*x + y - 5*z"
+    assert expr.to_string_ordered(pulp_rs.TermOrder.Alphabetical) == "3*x + y - 5*z"
+    assert expr.to_string_ordered(pulp_rs.TermOrder.Insertion) == "y - 5*z + 3*x"
+    assert expr.to_string_ordered(pulp_rs.TermOrder.ByCoefficient) == "-5*z + 3*x + y"
+
+
+def test_lp_affine_simplify():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    # (x + y) - y
+    expr = pulp_rs.LpAffineExpression([(x, 1), (y, 1)])
+    expr.addTerm(y, -1)
+    assert len(expr.terms()) == 2
+
+    expr.simplify()
+    assert [k.name for k, _ in expr.terms()] == ["x"]
+    assert str(expr) == "x"
+
+
+def test_lp_affine_terms():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    expr = pulp_rs.LpAffineExpression([(y, 3), (x, 2)], constant=1.0)
+
+    d = expr.to_dict()
+    assert [(k.name, v) for k, v in d.items()] == [("y", 3), ("x", 2)]
+    assert [(k.name, v) for k, v in expr.terms()] == [("y", 3), ("x", 2)]
+
+    rebuilt = pulp_rs.LpAffineExpression(d, constant=expr.constant)
+    assert str(rebuilt) == str(expr)
+
+def test_lp_affine_to_dict_list():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    expr = pulp_rs.LpAffineExpression([(y, 3), (x, 2)])
+    # pulp.LpAffineExpression({x: 2, y: 3}).toDict()
+    fixture = [{"name": "x", "value": 2}, {"name": "y", "value": 3}]
+    assert expr.to_dict_list() == fixture
+    assert json.loads(json.dumps(expr.to_dict_list())) == fixture
+
+
+def test_lp_dot():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    assert str(pulp_rs.lpDot([2, 3], [x, y])) == "2*x + 3*y"
+    with pytest.raises(ValueError):
+        pulp_rs.lpDot([2, 3], [x])
+
+    v = pulp_rs.LpVariable("v")
+    w = pulp_rs.LpVariable("w")
+    assert str(pulp_rs.lpDot([2, 3], [v, w])) == "2*v + 3*w"
+    xs = pulp_rs.LpVariable.range("x", 0, 3)
+    assert str(pulp_rs.lpDot([1, 2, 3], xs)) == "x_0 + 2*x_1 + 3*x_2"
+
+
+def test_lp_affine_sorted_keys_ties():
+    x = pulp_rs.LpElement("x")
+    y = pulp_rs.LpElement("y")
+    anonymous = pulp_rs.LpElement(None)
+    empty = pulp_rs.LpElement("")
+
+    expr = pulp_rs.LpAffineExpression([(y, 1), (anonymous, 2), (x, 3), (empty, 4)])
+    assert [k.name for k in expr.sorted_keys()] == [None, "", "x", "y"]
+
+    expr = pulp_rs.LpAffineExpression([(empty, 4), (x, 3), (anonymous, 2), (y, 1)])
+    assert [k.name for k in expr.sorted_keys()] == ["", None, "x", "y"]
+
+
+def test_value():
+    assert pulp_rs.value(None) is None
+    assert pulp_rs.value(3.5) == 3.5
+
+    x = pulp_rs.LpVariable("x")
+    assert pulp_rs.value(x) is None
+    x.var_value = 2.0
+    assert pulp_rs.value(x) == 2.0
+
+    assert pulp_rs.value(pulp_rs.LpAffineExpression(constant=5.0)) == 5.0
+    # elements carry no value, so the expression is unsolved
+    expr = pulp_rs.LpAffineExpression({pulp_rs.LpElement("y"): 2}, constant=1.0)
+    assert pulp_rs.value(expr) is None
+
+
+def test_lp_variable_bounds_validation():
+    with pytest.raises(ValueError):
+        pulp_rs.LpVariable("x", low_bound=5, up_bound=1)
+    with pytest.raises(ValueError):
+        pulp_rs.LpVariable("b", up_bound=2, cat="Binary")
+    with pytest.raises(ValueError):
+        pulp_rs.LpVariable("b", low_bound=0.5, cat="Binary")
+
+    b = pulp_rs.LpVariable("b", cat="Binary")
+    assert (b.low_bound, b.up_bound, b.cat) == (0, 1, "Integer")
+
+    x = pulp_rs.LpVariable("x", low_bound=0, up_bound=10)
+    x.up_bound = -1
+    with pytest.raises(ValueError):
+        x.validate()
+
+def test_lp_variable_range():
+    xs = pulp_rs.LpVariable.range("x", 0, 5, low_bound=0, cat="Integer")
+    assert [x.name for x in xs] == ["x_0", "x_1", "x_2", "x_3", "x_4"]
+    assert all(x.low_bound == 0 and x.cat == "Integer" for x in xs)
+
+    ys = pulp_rs.LpVariable.range("y[a]", 1, 3)
+    assert len({y.name for y in ys}) == 2
+    assert all("[" not in y.name for y in ys)
+
+
+def test_lp_variable_category_conversion():
+    x = pulp_rs.LpVariable("x", low_bound=2, up_bound=8)
+
+    x.toInteger()
+    assert (x.cat, x.low_bound, x.up_bound) == ("Integer", 2, 8)
+
+    x.toBinary()
+    assert (x.cat, x.low_bound, x.up_bound) == ("Integer", 0, 1)
+
+    x.toContinuous()
+    assert (x.cat, x.low_bound, x.up_bound) == ("Continuous", 2, 8)
+
+    # toInteger keeps the binary bounds
+    x.toBinary()
+    x.toInteger()
+    assert (x.cat, x.low_bound, x.up_bound) == ("Integer", 0, 1)
+    x.toContinuous()
+    assert (x.cat, x.low_bound, x.up_bound) == ("Continuous", 2, 8)
+
+    # bounds edited after toBinary are not overwritten
+    x.toBinary()
+    x.up_bound = 0
+    x.toContinuous()
+    assert (x.cat, x.low_bound, x.up_bound) == ("Continuous", 0, 0)
+    x.toContinuous()
+    assert (x.low_bound, x.up_bound) == (0, 0)
+
+
+def test_lp_variable_pulp_names():
+    v = pulp_rs.LpVariable("v", low_bound=0, up_bound=4)
+    assert v.varValue is None
+    v.var_value = 3.0
+    assert v.varValue == 3.0
+    v.varValue = 2.0
+    assert v.var_value == 2.0
+
+    assert (v.lowBound, v.upBound) == (0, 4)
+    v.upBound = 5
+    assert v.up_bound == 5
+    assert v.dj is None
+
+
+def test_lp_variable_expression():
+    c = pulp_rs.LpElement("c")
+    e = pulp_rs.LpAffineExpression({c: 2})
+    x = pulp_rs.LpVariable("x", e=e)
+    assert str(x.expression) == str(e)
+
+    y = pulp_rs.LpVariable("y")
+    assert y.expression is None
+    y.add_expression(e)
+    assert str(y.expression) == "2*c"
+
+
+def test_lp_variable_feasible_round():
+    x = pulp_rs.LpVariable("x", low_bound=0, up_bound=10, cat="Integer")
+    x.var_value = 3.6
+    assert x.feasible_round() == 4
+    x.var_value = 10.7
+    assert x.feasible_round() == 10
+
+    # no integer fits within [2.5, 2.7]
+    y = pulp_rs.LpVariable("y", low_bound=2.5, up_bound=2.7, cat="Integer")
+    y.var_value = 2.6
+    with pytest.raises(ValueError):
+        y.feasible_round()
+
+    z = pulp_rs.LpVariable("z", cat="Integer")
+    z.var_value = float("nan")
+    with pytest.raises(ValueError):
+        z.feasible_round()
+
 
 @pytest.mark.skip(reason="Unfinished implementation")
 def test_var_value_or_default():